@@ -149,13 +149,16 @@ impl FromWorld for GameOfLifePipeline {
         let shader = world
             .resource::<AssetServer>()
             .load("shaders/game_of_life.wgsl");
+        // the shader's workgroup size comes from WORKGROUP_SIZE so the dispatch counts below
+        // always match what the pipeline was compiled with
+        let shader_defs = vec![ShaderDefVal::UInt("WORKGROUP_SIZE".into(), WORKGROUP_SIZE)];
         let pipeline_cache = world.resource::<PipelineCache>();
         let init_pipeline = pipeline_cache.queue_compute_pipeline(ComputePipelineDescriptor {
             label: None,
             layout: vec![texture_bind_group_layout.clone()],
             push_constant_ranges: Vec::new(),
             shader: shader.clone(),
-            shader_defs: vec![],
+            shader_defs: shader_defs.clone(),
             entry_point: Cow::from("init"),
         });
         let update_pipeline = pipeline_cache.queue_compute_pipeline(ComputePipelineDescriptor {
@@ -163,7 +166,7 @@ impl FromWorld for GameOfLifePipeline {
             layout: vec![texture_bind_group_layout.clone()],
             push_constant_ranges: Vec::new(),
             shader,
-            shader_defs: vec![],
+            shader_defs,
             entry_point: Cow::from("update"),
         });
 